@@ -6,6 +6,9 @@ use node::*;
 
 mod side;
 
+mod monoid;
+pub use monoid::{AvlTreeMonoid, Op};
+
 pub struct AvlTree<T: Ord> {
     root: Tree<T>,
     length: usize,
@@ -57,6 +60,22 @@ impl<T: Ord> AvlTree<T> {
         self.length == 0
     }
 
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.length {
+            return None;
+        }
+
+        self.root.as_ref().and_then(|n| n.get(index))
+    }
+
+    pub fn select(&self, k: usize) -> Option<&T> {
+        self.get(k)
+    }
+
+    pub fn rank(&self, value: &T) -> usize {
+        self.root.as_ref().map_or(0, |n| n.rank(value))
+    }
+
     fn node_iter(&self) -> NodeIter<T> {
         let cap = self.root.as_ref().map_or(0, |n| n.height);
 
@@ -78,6 +97,82 @@ impl<T: Ord> AvlTree<T> {
             node_iter: self.node_iter()
         }
     }
+
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.length.checked_sub(1).and_then(|last| self.get(last))
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let node = Box::new(Node {
+            value,
+            height: 1,
+            size: 1,
+            left: None,
+            right: None,
+        });
+
+        self.root = merge_trees(Some(node), self.root.take());
+        self.length += 1;
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        let node = Box::new(Node {
+            value,
+            height: 1,
+            size: 1,
+            left: None,
+            right: None,
+        });
+
+        self.root = merge_trees(self.root.take(), Some(node));
+        self.length += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let (_, removed, right) = split_delete(self.root.take(), 0)?;
+
+        self.root = right;
+        self.length -= 1;
+
+        Some(removed)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let last = self.length.checked_sub(1)?;
+        let (left, removed, _) = split_delete(self.root.take(), last)?;
+
+        self.root = left;
+        self.length -= 1;
+
+        Some(removed)
+    }
+
+    /// Splits the sequence by position (not key order) into the elements
+    /// before `index` and the elements from `index` onward.
+    pub fn split_at(&mut self, index: usize) -> (AvlTree<T>, AvlTree<T>) {
+        let left_len = index.min(self.length);
+        let right_len = self.length - left_len;
+        let (left, right) = split(self.root.take(), left_len);
+
+        self.length = 0;
+
+        (
+            AvlTree { root: left, length: left_len },
+            AvlTree { root: right, length: right_len },
+        )
+    }
+
+    /// Moves every element of `other` onto the end of `self`, leaving
+    /// `other` empty.
+    pub fn append(&mut self, other: &mut AvlTree<T>) {
+        self.root = merge_trees(self.root.take(), other.root.take());
+        self.length += other.length;
+        other.length = 0;
+    }
 }
 
 fn insert<T: Ord>(tree: &mut Tree<T>, value: T) -> bool {
@@ -97,6 +192,7 @@ fn insert<T: Ord>(tree: &mut Tree<T>, value: T) -> bool {
         *tree = Some(Box::new(Node {
             value,
             height: 1,
+            size: 1,
             left: None,
             right: None,
         }));
@@ -133,13 +229,36 @@ fn remove<T: Ord>(tree: &mut Tree<T>, value: &T) -> bool {
 
 fn merge<T: Ord>(left: Box<Node<T>>, right: Box<Node<T>>) -> Box<Node<T>> {
     let mut op_right = Some(right);
-    let mut root = take_min(&mut op_right).unwrap();
+    let mid = take_min(&mut op_right).unwrap();
 
-    root.left = Some(left);
-    root.right = op_right;
-    root.rebalance();
+    join(Some(left), mid, op_right)
+}
 
-    root
+/// Joins `left`, `mid`, and `right` (in that left-to-right order) into one
+/// balanced tree. `left` and `right` may differ in height by more than the
+/// single level a plain `rebalance()` call can correct, so this descends
+/// the taller side's spine, re-joins at the bottom, and rebalances on the
+/// way back up, one level at a time.
+fn join<T: Ord>(left: Tree<T>, mut mid: Box<Node<T>>, right: Tree<T>) -> Box<Node<T>> {
+    let left_height = left.as_ref().map_or(0, |n| n.height);
+    let right_height = right.as_ref().map_or(0, |n| n.height);
+
+    if left_height > right_height + 1 {
+        let mut node = left.unwrap();
+        node.right = Some(join(node.right.take(), mid, right));
+        node.rebalance();
+        node
+    } else if right_height > left_height + 1 {
+        let mut node = right.unwrap();
+        node.left = Some(join(left, mid, node.left.take()));
+        node.rebalance();
+        node
+    } else {
+        mid.left = left;
+        mid.right = right;
+        mid.rebalance();
+        mid
+    }
 }
 
 fn take_min<T: Ord>(tree: &mut Tree<T>) -> Tree<T> {
@@ -159,6 +278,64 @@ fn take_min<T: Ord>(tree: &mut Tree<T>) -> Tree<T> {
     }
 }
 
+fn merge_trees<T: Ord>(left: Tree<T>, right: Tree<T>) -> Tree<T> {
+    match (left, right) {
+        (None, right) => right,
+        (left, None) => left,
+        (Some(left), Some(right)) => Some(merge(left, right)),
+    }
+}
+
+/// Splits the sequence by position into `[0, index)` and `[index, len)`,
+/// re-joining each half with its untouched sibling on the way back up the
+/// recursion (a plain `rebalance()` isn't enough here: the recursively
+/// split-off side can be arbitrarily shorter than the sibling it's paired
+/// with).
+fn split<T: Ord>(tree: Tree<T>, index: usize) -> (Tree<T>, Tree<T>) {
+    match tree {
+        None => (None, None),
+        Some(mut node) => {
+            let ls = node.left.as_ref().map_or(0, |n| n.size);
+
+            if index <= ls {
+                let (l, r) = split(node.left.take(), index);
+                let right = node.right.take();
+                (l, Some(join(r, node, right)))
+            } else {
+                let (l, r) = split(node.right.take(), index - ls - 1);
+                let left = node.left.take();
+                (Some(join(left, node, l)), r)
+            }
+        }
+    }
+}
+
+/// Removes the element at `index`, returning the elements before it, the
+/// removed element itself, and the elements after it.
+fn split_delete<T: Ord>(tree: Tree<T>, index: usize) -> Option<(Tree<T>, T, Tree<T>)> {
+    let mut node = tree?;
+    let ls = node.left.as_ref().map_or(0, |n| n.size);
+
+    match index.cmp(&ls) {
+        Ordering::Less => {
+            let (l, value, r) = split_delete(node.left.take(), index)?;
+            let right = node.right.take();
+            Some((l, value, Some(join(r, node, right))))
+        }
+        Ordering::Equal => {
+            let left = node.left.take();
+            let right = node.right.take();
+            let Node { value, .. } = *node;
+            Some((left, value, right))
+        }
+        Ordering::Greater => {
+            let (l, value, r) = split_delete(node.right.take(), index - ls - 1)?;
+            let left = node.left.take();
+            Some((Some(join(left, node, l)), value, r))
+        }
+    }
+}
+
 impl<T: Ord> Default for AvlTree<T> {
     fn default() -> Self {
         Self::new()
@@ -0,0 +1,301 @@
+use std::cmp::max;
+use std::cmp::Ordering;
+use std::iter::FromIterator;
+use std::mem;
+use super::side::*;
+
+/// A monoid over the values stored in an [`AvlTreeMonoid`], used to answer
+/// aggregate queries (sum, max, ...) over arbitrary key ranges in `O(log n)`.
+pub trait Op<T> {
+    type Summary: Clone;
+
+    fn summarize(value: &T) -> Self::Summary;
+    fn op(a: Self::Summary, b: Self::Summary) -> Self::Summary;
+    fn empty() -> Self::Summary;
+}
+
+type Tree<T, O> = Option<Box<Node<T, O>>>;
+
+struct Node<T: Ord, O: Op<T>> {
+    value: T,
+    height: usize,
+    size: usize,
+    summary: O::Summary,
+    left: Tree<T, O>,
+    right: Tree<T, O>,
+}
+
+impl<T: Ord, O: Op<T>> Node<T, O> {
+    fn child(&self, side: Side) -> &Tree<T, O> {
+        match side {
+            Side::Left => &self.left,
+            Side::Right => &self.right,
+        }
+    }
+
+    fn child_mut(&mut self, side: Side) -> &mut Tree<T, O> {
+        match side {
+            Side::Left => &mut self.left,
+            Side::Right => &mut self.right,
+        }
+    }
+
+    fn height(&self, side: Side) -> usize {
+        self.child(side).as_ref().map_or(0, |n| n.height)
+    }
+
+    fn size(&self, side: Side) -> usize {
+        self.child(side).as_ref().map_or(0, |n| n.size)
+    }
+
+    fn summary(&self, side: Side) -> O::Summary {
+        self.child(side).as_ref().map_or_else(O::empty, |n| n.summary.clone())
+    }
+
+    fn balance_factor(&self) -> i8 {
+        let (left, right) = (self.height(Side::Left), self.height(Side::Right));
+
+        if left < right {
+            (right - left) as i8
+        } else {
+            -((left - right) as i8)
+        }
+    }
+
+    fn update_metadata(&mut self) {
+        self.height = 1 + max(self.height(Side::Left), self.height(Side::Right));
+        self.size = 1 + self.size(Side::Left) + self.size(Side::Right);
+        self.summary = O::op(
+            self.summary(Side::Left),
+            O::op(O::summarize(&self.value), self.summary(Side::Right)),
+        );
+    }
+
+    fn rotate(&mut self, side: Side) {
+        let mut subtree = self.child_mut(!side).take().unwrap();
+
+        *self.child_mut(!side) = subtree.child_mut(side).take();
+        self.update_metadata();
+
+        mem::swap(self, subtree.as_mut());
+
+        *self.child_mut(side) = Some(subtree);
+        self.update_metadata();
+    }
+
+    fn rebalance(&mut self) {
+        self.update_metadata();
+
+        let side = match self.balance_factor() {
+            -2 => Side::Left,
+            2 => Side::Right,
+            _ => return,
+        };
+
+        let subtree = self.child_mut(side).as_mut().unwrap();
+
+        if let (Side::Left, 1) | (Side::Right, -1) = (side, subtree.balance_factor()) {
+            subtree.rotate(side);
+        }
+
+        self.rotate(!side);
+    }
+}
+
+/// An [`AvlTree`](super::AvlTree) augmented with a cached monoid summary per
+/// subtree, so [`fold_range`](AvlTreeMonoid::fold_range) can aggregate `[lo,
+/// hi)` in `O(log n)` instead of walking every element in range.
+pub struct AvlTreeMonoid<T: Ord, O: Op<T>> {
+    root: Tree<T, O>,
+    length: usize,
+}
+
+impl<T: Ord, O: Op<T>> AvlTreeMonoid<T, O> {
+    pub fn new() -> Self {
+        AvlTreeMonoid {
+            root: None,
+            length: 0,
+        }
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let mut current = &self.root;
+
+        while let Some(node) = current {
+            current = match value.cmp(&node.value) {
+                Ordering::Equal => return true,
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+            }
+        }
+
+        false
+    }
+
+    pub fn insert(&mut self, value: T) -> bool {
+        let inserted = insert(&mut self.root, value);
+
+        if inserted {
+            self.length += 1;
+        }
+
+        inserted
+    }
+
+    pub fn remove(&mut self, value: &T) -> bool {
+        let removed = remove(&mut self.root, value);
+
+        if removed {
+            self.length -= 1;
+        }
+
+        removed
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Aggregates `O::summarize` over every stored value in `[lo, hi)`,
+    /// combining fully-contained subtrees via their cached summary instead
+    /// of visiting each element.
+    pub fn fold_range(&self, lo: &T, hi: &T) -> O::Summary {
+        fold_range(&self.root, lo, hi)
+    }
+}
+
+impl<T: Ord, O: Op<T>> Default for AvlTreeMonoid<T, O> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord, O: Op<T>> FromIterator<T> for AvlTreeMonoid<T, O> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut tree = AvlTreeMonoid::new();
+
+        for value in iter {
+            tree.insert(value);
+        }
+
+        tree
+    }
+}
+
+fn insert<T: Ord, O: Op<T>>(tree: &mut Tree<T, O>, value: T) -> bool {
+    if let Some(node) = tree {
+        let inserted = match value.cmp(&node.value) {
+            Ordering::Equal => false,
+            Ordering::Less => insert(&mut node.left, value),
+            Ordering::Greater => insert(&mut node.right, value),
+        };
+
+        if inserted {
+            node.rebalance();
+        }
+
+        inserted
+    } else {
+        *tree = Some(Box::new(Node {
+            summary: O::summarize(&value),
+            value,
+            height: 1,
+            size: 1,
+            left: None,
+            right: None,
+        }));
+
+        true
+    }
+}
+
+fn remove<T: Ord, O: Op<T>>(tree: &mut Tree<T, O>, value: &T) -> bool {
+    if let Some(node) = tree {
+        let removed = match value.cmp(&node.value) {
+            Ordering::Less => remove(&mut node.left, value),
+            Ordering::Greater => remove(&mut node.right, value),
+            Ordering::Equal => {
+                *tree = match (node.left.take(), node.right.take()) {
+                    (None, None) => None,
+                    (Some(b), None) | (None, Some(b)) => Some(b),
+                    (Some(left), Some(right)) => Some(merge(left, right)),
+                };
+
+                return true;
+            }
+        };
+
+        if removed {
+            node.rebalance();
+        }
+
+        removed
+    } else {
+        false
+    }
+}
+
+fn merge<T: Ord, O: Op<T>>(left: Box<Node<T, O>>, right: Box<Node<T, O>>) -> Box<Node<T, O>> {
+    let mut op_right = Some(right);
+    let mut root = take_min(&mut op_right).unwrap();
+
+    root.left = Some(left);
+    root.right = op_right;
+    root.rebalance();
+
+    root
+}
+
+fn take_min<T: Ord, O: Op<T>>(tree: &mut Tree<T, O>) -> Tree<T, O> {
+    if let Some(mut node) = tree.take() {
+        if let Some(small) = take_min(&mut node.left) {
+            node.rebalance();
+            *tree = Some(node);
+
+            Some(small)
+        } else {
+            *tree = node.right.take();
+
+            Some(node)
+        }
+    } else {
+        None
+    }
+}
+
+/// Summary of the subtree restricted to values in `[lo, hi)`.
+fn fold_range<T: Ord, O: Op<T>>(tree: &Tree<T, O>, lo: &T, hi: &T) -> O::Summary {
+    match tree {
+        None => O::empty(),
+        Some(node) if node.value < *lo => fold_range(&node.right, lo, hi),
+        Some(node) if node.value >= *hi => fold_range(&node.left, lo, hi),
+        Some(node) => O::op(
+            fold_ge(&node.left, lo),
+            O::op(O::summarize(&node.value), fold_lt(&node.right, hi)),
+        ),
+    }
+}
+
+/// Summary of the subtree restricted to values `>= lo`.
+fn fold_ge<T: Ord, O: Op<T>>(tree: &Tree<T, O>, lo: &T) -> O::Summary {
+    match tree {
+        None => O::empty(),
+        Some(node) if node.value < *lo => fold_ge(&node.right, lo),
+        Some(node) => O::op(
+            fold_ge(&node.left, lo),
+            O::op(O::summarize(&node.value), node.summary(Side::Right)),
+        ),
+    }
+}
+
+/// Summary of the subtree restricted to values `< hi`.
+fn fold_lt<T: Ord, O: Op<T>>(tree: &Tree<T, O>, hi: &T) -> O::Summary {
+    match tree {
+        None => O::empty(),
+        Some(node) if node.value >= *hi => fold_lt(&node.left, hi),
+        Some(node) => O::op(
+            node.summary(Side::Left),
+            O::op(O::summarize(&node.value), fold_lt(&node.right, hi)),
+        ),
+    }
+}
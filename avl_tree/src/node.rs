@@ -7,6 +7,7 @@ pub type Tree<T> = Option<Box<Node<T>>>;
 pub struct Node<T: Ord> {
     pub value: T,
     pub height: usize,
+    pub size: usize,
     pub left: Tree<T>,
     pub right: Tree<T>,
 }
@@ -30,6 +31,10 @@ impl<T: Ord> Node<T> {
         self.child(side).as_ref().map_or(0, |n| n.height)
     }
 
+    fn size(&self, side: Side) -> usize {
+        self.child(side).as_ref().map_or(0, |n| n.size)
+    }
+
     fn balance_factor(&self) -> i8 {
         let (left, right) = (self.height(Side::Left), self.height(Side::Right));
 
@@ -40,24 +45,25 @@ impl<T: Ord> Node<T> {
         }
     }
 
-    fn update_height(&mut self) {
+    fn update_metadata(&mut self) {
         self.height = 1 + max(self.height(Side::Left), self.height(Side::Right));
+        self.size = 1 + self.size(Side::Left) + self.size(Side::Right);
     }
 
     fn rotate(&mut self, side: Side) {
         let mut subtree = self.child_mut(!side).take().unwrap();
 
         *self.child_mut(!side) = subtree.child_mut(side).take();
-        self.update_height();
+        self.update_metadata();
 
         mem::swap(self, subtree.as_mut());
 
         *self.child_mut(side) = Some(subtree);
-        self.update_height();
+        self.update_metadata();
     }
 
     pub fn rebalance(&mut self) {
-        self.update_height();
+        self.update_metadata();
 
         let side = match self.balance_factor() {
             -2 => Side::Left,
@@ -73,4 +79,25 @@ impl<T: Ord> Node<T> {
 
         self.rotate(!side);
     }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let left_size = self.left.as_ref().map_or(0, |n| n.size);
+
+        match index.cmp(&left_size) {
+            std::cmp::Ordering::Less => self.left.as_ref().and_then(|n| n.get(index)),
+            std::cmp::Ordering::Equal => Some(&self.value),
+            std::cmp::Ordering::Greater => {
+                self.right.as_ref().and_then(|n| n.get(index - left_size - 1))
+            }
+        }
+    }
+
+    pub fn rank(&self, value: &T) -> usize {
+        match value.cmp(&self.value) {
+            std::cmp::Ordering::Greater => {
+                self.size(Side::Left) + 1 + self.right.as_ref().map_or(0, |n| n.rank(value))
+            }
+            _ => self.left.as_ref().map_or(0, |n| n.rank(value)),
+        }
+    }
 }
@@ -1,10 +1,11 @@
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
 use std::cell::RefCell;
 
 #[derive(Debug, Clone)]
 struct Node<T: Clone> {
     data: T,
     next: Link<T>,
+    prev: WeakLink<T>,
 }
 
 impl<T: Clone> Node<T> {
@@ -12,11 +13,22 @@ impl<T: Clone> Node<T> {
         Rc::new(RefCell::new(Node {
             data,
             next: None,
+            prev: Weak::new(),
         }))
     }
 }
 
 type Link<T> = Option<Rc<RefCell<Node<T>>>>;
+type WeakLink<T> = Weak<RefCell<Node<T>>>;
+
+/// Extracts a detached node's data without assuming it's the sole owner:
+/// a live `Cursor` can still hold a clone of the same `Rc`, in which case
+/// we fall back to cloning out of the `RefCell` instead of unwrapping it.
+fn take_data<T: Clone>(node: Rc<RefCell<Node<T>>>) -> T {
+    Rc::try_unwrap(node)
+        .map(|cell| cell.into_inner().data)
+        .unwrap_or_else(|rc| rc.borrow().data.clone())
+}
 
 #[derive(Debug, Clone)]
 pub struct LinkedList<T: Clone> {
@@ -38,10 +50,13 @@ impl<T: Clone> LinkedList<T> {
         let new_node = Node::new(value);
 
         match self.tail.take() {
-            Some(tail) => tail.borrow_mut().next = Some(new_node.clone()),
+            Some(tail) => {
+                new_node.borrow_mut().prev = Rc::downgrade(&tail);
+                tail.borrow_mut().next = Some(new_node.clone());
+            }
             None => self.head = Some(new_node.clone()),
         }
-        
+
         self.tail = Some(new_node);
         self.length += 1;
     }
@@ -50,7 +65,10 @@ impl<T: Clone> LinkedList<T> {
         let new_node = Node::new(value);
 
         match self.head.take() {
-            Some(head) => new_node.borrow_mut().next = Some(head.clone()),
+            Some(head) => {
+                head.borrow_mut().prev = Rc::downgrade(&new_node);
+                new_node.borrow_mut().next = Some(head.clone());
+            }
             None => self.tail = Some(new_node.clone()),
         }
 
@@ -61,31 +79,125 @@ impl<T: Clone> LinkedList<T> {
     pub fn pop(&mut self) -> Option<T> {
         self.head.take().map(|head| {
             if let Some(node) = head.borrow_mut().next.take() {
+                node.borrow_mut().prev = Weak::new();
                 self.head = Some(node);
             } else {
                 self.tail.take();
             }
 
             self.length -= 1;
-            Rc::try_unwrap(head).ok()
-                .unwrap()
-                .into_inner().data
+            take_data(head)
+        })
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.take().map(|tail| {
+            match tail.borrow().prev.upgrade() {
+                Some(prev) => {
+                    prev.borrow_mut().next = None;
+                    self.tail = Some(prev);
+                }
+                None => {
+                    self.head.take();
+                }
+            }
+
+            self.length -= 1;
+            take_data(tail)
         })
     }
 
+    pub fn cursor_front(&self) -> Option<Cursor<T>> {
+        self.head.clone().map(Cursor)
+    }
+
+    pub fn cursor_back(&self) -> Option<Cursor<T>> {
+        self.tail.clone().map(Cursor)
+    }
+
+    /// Inserts `value` immediately after the node `cursor` points to.
+    pub fn insert_after(&mut self, cursor: &Cursor<T>, value: T) {
+        let new_node = Node::new(value);
+        let node = &cursor.0;
+
+        match node.borrow_mut().next.take() {
+            Some(next) => {
+                next.borrow_mut().prev = Rc::downgrade(&new_node);
+                new_node.borrow_mut().next = Some(next);
+            }
+            None => self.tail = Some(new_node.clone()),
+        }
+
+        new_node.borrow_mut().prev = Rc::downgrade(node);
+        node.borrow_mut().next = Some(new_node);
+
+        self.length += 1;
+    }
+
+    /// Removes the node `cursor` points to and returns its value.
+    pub fn remove(&mut self, cursor: Cursor<T>) -> T {
+        let node = cursor.0;
+        let prev = node.borrow().prev.upgrade();
+        let next = node.borrow_mut().next.take();
+
+        match &prev {
+            Some(prev) => prev.borrow_mut().next = next.clone(),
+            None => self.head = next.clone(),
+        }
+
+        match &next {
+            Some(next) => {
+                next.borrow_mut().prev = prev.as_ref().map_or_else(Weak::new, Rc::downgrade)
+            }
+            None => self.tail = prev,
+        }
+
+        self.length -= 1;
+
+        take_data(node)
+    }
+
     pub fn iter(&self) -> Iter<T> {
-        Iter::new(self.head.clone())
+        Iter::new(self.head.clone(), self.tail.clone(), self.length)
+    }
+}
+
+impl<T: Clone> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An opaque handle to a node, used to splice the list in place without
+/// re-walking it from the head.
+pub struct Cursor<T: Clone>(Rc<RefCell<Node<T>>>);
+
+impl<T: Clone> Cursor<T> {
+    pub fn get(&self) -> T {
+        self.0.borrow().data.clone()
+    }
+
+    pub fn next(&self) -> Option<Cursor<T>> {
+        self.0.borrow().next.clone().map(Cursor)
+    }
+
+    pub fn prev(&self) -> Option<Cursor<T>> {
+        self.0.borrow().prev.upgrade().map(Cursor)
     }
 }
 
 pub struct Iter<T: Clone> {
-    current: Link<T>,
+    front: Link<T>,
+    back: Link<T>,
+    remaining: usize,
 }
 
 impl<T: Clone> Iter<T> {
-    fn new(start: Link<T>) -> Self {
+    fn new(front: Link<T>, back: Link<T>, remaining: usize) -> Self {
         Iter {
-            current: start,
+            front,
+            back,
+            remaining,
         }
     }
 }
@@ -94,19 +206,33 @@ impl<T: Clone> Iterator for Iter<T> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let current = &self.current;
-        let mut result = None;
+        if self.remaining == 0 {
+            return None;
+        }
 
-        self.current = match current {
-            Some(ref current) => {
-                let current = current.borrow();
-                result = Some(current.data.clone());
-                current.next.clone()
-            },
-            None => None,
-        };
+        self.front.take().map(|front| {
+            self.remaining -= 1;
+            let data = front.borrow().data.clone();
+            self.front = front.borrow().next.clone();
 
-        result
+            data
+        })
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for Iter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.back.take().map(|back| {
+            self.remaining -= 1;
+            let data = back.borrow().data.clone();
+            self.back = back.borrow().prev.upgrade();
+
+            data
+        })
     }
 }
 
@@ -115,7 +241,6 @@ impl<T: Clone> IntoIterator for LinkedList<T> {
     type IntoIter = Iter<T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        Iter::new(self.head)
+        Iter::new(self.head, self.tail, self.length)
     }
 }
-
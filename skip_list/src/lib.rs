@@ -24,6 +24,7 @@ impl<T: Clone> Node<T> {
 pub struct SkipList<T: Clone> {
     head: Link<T>,
     tails: Vec<Link<T>>,
+    sorted_head: Vec<Link<T>>,
     max_level: usize,
     pub length: u64,
 }
@@ -33,6 +34,7 @@ impl<T: Clone> SkipList<T> {
         SkipList {
             head: None,
             tails: vec![None; max_level + 1],
+            sorted_head: vec![None; max_level + 1],
             max_level,
             length: 0,
         }
@@ -71,7 +73,7 @@ impl<T: Clone> SkipList<T> {
         self.length += 1;
     }
 
-    pub fn search(&self, id: u64) -> Option<T> {
+    pub fn search_by_id(&self, id: u64) -> Option<T> {
         match self.head {
             Some(ref head) => {
                 let mut start_level = self.max_level;
@@ -113,6 +115,107 @@ impl<T: Clone> SkipList<T> {
     }
 }
 
+impl<T: Ord + Clone> SkipList<T> {
+    /// Returns, for every level, the last node whose value is `< value`
+    /// (or `None` if no such node exists, meaning `sorted_head` is the
+    /// predecessor at that level).
+    fn predecessors(&self, value: &T) -> Vec<Link<T>> {
+        let mut update = vec![None; self.max_level + 1];
+        let mut current: Link<T> = None;
+
+        for level in (0..=self.max_level).rev() {
+            loop {
+                let next = match &current {
+                    Some(node) => node.borrow().next.get(level).cloned().flatten(),
+                    None => self.sorted_head[level].clone(),
+                };
+
+                match next {
+                    Some(next) if next.borrow().data < *value => current = Some(next),
+                    _ => break,
+                }
+            }
+
+            update[level] = current.clone();
+        }
+
+        update
+    }
+
+    /// Inserts `value` keeping the list ordered by `T`, splicing the new
+    /// node into the `next` pointers recorded by `predecessors` at every
+    /// level of its randomly chosen height.
+    pub fn insert(&mut self, value: T) {
+        let update = self.predecessors(&value);
+        let height = 1 + self.get_level();
+        let new_node = Node::new(value, vec![None; height], 0);
+
+        for (level, pred) in update.iter().enumerate().take(height) {
+            match pred {
+                Some(pred) => {
+                    let mut pred = pred.borrow_mut();
+                    new_node.borrow_mut().next[level] = pred.next[level].take();
+                    pred.next[level] = Some(new_node.clone());
+                }
+                None => {
+                    new_node.borrow_mut().next[level] = self.sorted_head[level].take();
+                    self.sorted_head[level] = Some(new_node.clone());
+                }
+            }
+        }
+
+        self.length += 1;
+    }
+
+    pub fn search(&self, value: &T) -> Option<T> {
+        let update = self.predecessors(value);
+        let candidate = match &update[0] {
+            Some(pred) => pred.borrow().next[0].clone(),
+            None => self.sorted_head[0].clone(),
+        };
+
+        candidate
+            .filter(|node| node.borrow().data == *value)
+            .map(|node| node.borrow().data.clone())
+    }
+
+    /// Iterates every stored value in `[lo, hi)` in ascending order.
+    pub fn range(&self, lo: &T, hi: &T) -> RangeIter<T> {
+        let update = self.predecessors(lo);
+        let start = match &update[0] {
+            Some(pred) => pred.borrow().next[0].clone(),
+            None => self.sorted_head[0].clone(),
+        };
+
+        RangeIter {
+            current: start,
+            hi: hi.clone(),
+        }
+    }
+}
+
+pub struct RangeIter<T: Ord + Clone> {
+    current: Link<T>,
+    hi: T,
+}
+
+impl<T: Ord + Clone> Iterator for RangeIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.current.take()?;
+        let node = node.borrow();
+
+        if node.data >= self.hi {
+            return None;
+        }
+
+        self.current = node.next[0].clone();
+
+        Some(node.data.clone())
+    }
+}
+
 pub struct Iter<T: Clone> {
     current: Link<T>,
     level: usize,